@@ -0,0 +1,53 @@
+use crate::executor::query::{ColumnType, Value};
+use crate::DbErr;
+use indexmap::IndexMap;
+use std::convert::TryFrom;
+
+/// An in-memory row used by the `mock` test backend. Holds one [`Value`] per
+/// column, in select order, so it can answer both name- and index-based lookups
+/// the same way the sqlx-backed [`crate::QueryResultRow`] variants do.
+#[derive(Debug, Clone, Default)]
+pub struct MockRow {
+    values: IndexMap<String, Value>,
+}
+
+impl MockRow {
+    pub fn new(values: IndexMap<String, Value>) -> Self {
+        Self { values }
+    }
+
+    pub fn try_get<T>(&self, col: &str) -> Result<T, DbErr>
+    where
+        T: TryFrom<Value>,
+    {
+        let value = self.values.get(col).cloned().unwrap_or(Value::Null);
+        T::try_from(value)
+            .map_err(|_| DbErr::Query(format!("Failed to get `{}` from mock row", col)))
+    }
+
+    pub fn try_get_by_index<T>(&self, index: usize) -> Result<T, DbErr>
+    where
+        T: TryFrom<Value>,
+    {
+        let value = self
+            .values
+            .get_index(index)
+            .map(|(_, v)| v.clone())
+            .unwrap_or(Value::Null);
+        T::try_from(value)
+            .map_err(|_| DbErr::Query(format!("Failed to get column {} from mock row", index)))
+    }
+
+    /// Names of every column in this row, in select order.
+    pub fn column_names(&self) -> Vec<String> {
+        self.values.keys().cloned().collect()
+    }
+
+    /// The SQL type of `col`, inferred from the [`Value`] stored for it.
+    pub fn column_type(&self, col: &str) -> Result<ColumnType, DbErr> {
+        self.values
+            .get(col)
+            .map(ColumnType::from_value)
+            .ok_or_else(|| DbErr::Query(format!("No column found for `{}`", col)))
+    }
+}
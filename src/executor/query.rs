@@ -37,6 +37,15 @@ pub trait TryGetable {
         Self: Sized;
 }
 
+/// Positional counterpart of [`TryGetable`], for reading a column by its ordinal
+/// index rather than by name. Useful when consuming raw `SELECT` result sets whose
+/// column aliases are unknown or duplicated.
+pub trait TryGetableByIndex {
+    fn try_get_by_index(res: &QueryResult, index: usize) -> Result<Self, TryGetError>
+    where
+        Self: Sized;
+}
+
 // QueryResult //
 
 impl QueryResult {
@@ -46,8 +55,465 @@ impl QueryResult {
     {
         Ok(T::try_get(self, pre, col)?)
     }
+
+    pub fn try_get_by<T>(&self, index: usize) -> Result<T, DbErr>
+    where
+        T: TryGetableByIndex,
+    {
+        Ok(T::try_get_by_index(self, index)?)
+    }
+
+    /// Names of every column in this row, in select order.
+    pub fn column_names(&self) -> Vec<String> {
+        match &self.row {
+            #[cfg(feature = "sqlx-mysql")]
+            QueryResultRow::SqlxMySql(row) => {
+                use sqlx::Column;
+                row.columns().iter().map(|c| c.name().to_owned()).collect()
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            QueryResultRow::SqlxPostgres(row) => {
+                use sqlx::Column;
+                row.columns().iter().map(|c| c.name().to_owned()).collect()
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            QueryResultRow::SqlxSqlite(row) => {
+                use sqlx::Column;
+                row.columns().iter().map(|c| c.name().to_owned()).collect()
+            }
+            #[cfg(feature = "mock")]
+            QueryResultRow::Mock(row) => row.column_names(),
+        }
+    }
+
+    /// The SQL type of `col`, as reported by the driver.
+    pub fn column_type(&self, col: &str) -> Result<ColumnType, DbErr> {
+        match &self.row {
+            #[cfg(feature = "sqlx-mysql")]
+            QueryResultRow::SqlxMySql(row) => {
+                use sqlx::{Column, Row, TypeInfo};
+                let column = row
+                    .columns()
+                    .iter()
+                    .find(|c| c.name() == col)
+                    .ok_or_else(|| DbErr::Query(format!("No column found for `{}`", col)))?;
+                Ok(ColumnType::from_type_name(column.type_info().name()))
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            QueryResultRow::SqlxPostgres(row) => {
+                use sqlx::{Column, Row, TypeInfo};
+                let column = row
+                    .columns()
+                    .iter()
+                    .find(|c| c.name() == col)
+                    .ok_or_else(|| DbErr::Query(format!("No column found for `{}`", col)))?;
+                Ok(ColumnType::from_type_name(column.type_info().name()))
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            QueryResultRow::SqlxSqlite(row) => {
+                use sqlx::{Column, Row, TypeInfo};
+                let column = row
+                    .columns()
+                    .iter()
+                    .find(|c| c.name() == col)
+                    .ok_or_else(|| DbErr::Query(format!("No column found for `{}`", col)))?;
+                Ok(ColumnType::from_type_name(column.type_info().name()))
+            }
+            #[cfg(feature = "mock")]
+            QueryResultRow::Mock(row) => row.column_type(col),
+        }
+    }
+
+    /// Raw-byte read used by [`Self::try_get_value`] for `BLOB`/`BYTEA` columns.
+    /// Kept out of the [`TryGetable`] trait so it doesn't collide with the blanket
+    /// `Vec<T>` impl used for native Postgres arrays.
+    fn try_get_bytes(&self, pre: &str, col: &str) -> Result<Option<Vec<u8>>, DbErr> {
+        let column = format!("{}{}", pre, col);
+        match &self.row {
+            #[cfg(feature = "sqlx-mysql")]
+            QueryResultRow::SqlxMySql(row) => {
+                use sqlx::Row;
+                row.try_get::<Option<Vec<u8>>, _>(column.as_str())
+                    .map_err(crate::sqlx_error_to_query_err)
+                    .map_err(Into::into)
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            QueryResultRow::SqlxPostgres(row) => {
+                use sqlx::Row;
+                row.try_get::<Option<Vec<u8>>, _>(column.as_str())
+                    .map_err(crate::sqlx_error_to_query_err)
+                    .map_err(Into::into)
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            QueryResultRow::SqlxSqlite(row) => {
+                use sqlx::Row;
+                row.try_get::<Option<Vec<u8>>, _>(column.as_str())
+                    .map_err(crate::sqlx_error_to_query_err)
+                    .map_err(Into::into)
+            }
+            #[cfg(feature = "mock")]
+            QueryResultRow::Mock(row) => match row.try_get::<Vec<u8>>(column.as_str()) {
+                Ok(v) => Ok(Some(v)),
+                Err(e) => {
+                    debug_print!("{:#?}", e.to_string());
+                    Ok(None)
+                }
+            },
+        }
+    }
+
+    /// Read `col` without knowing its Rust type ahead of time, dispatching on the
+    /// column's runtime SQL type to pick the matching [`TryGetable`] impl. Handy for
+    /// ad-hoc queries, row-to-map conversions, and admin/export tooling.
+    pub fn try_get_value(&self, pre: &str, col: &str) -> Result<Value, DbErr> {
+        let column = format!("{}{}", pre, col);
+        let value = match self.column_type(&column)? {
+            ColumnType::Bool => self.try_get::<Option<bool>>(pre, col)?.map(Value::Bool),
+            ColumnType::TinyInt => self.try_get::<Option<i8>>(pre, col)?.map(Value::TinyInt),
+            ColumnType::SmallInt => self.try_get::<Option<i16>>(pre, col)?.map(Value::SmallInt),
+            ColumnType::Int => self.try_get::<Option<i32>>(pre, col)?.map(Value::Int),
+            ColumnType::BigInt => self.try_get::<Option<i64>>(pre, col)?.map(Value::BigInt),
+            ColumnType::TinyUnsigned => self
+                .try_get::<Option<u8>>(pre, col)?
+                .map(Value::TinyUnsigned),
+            ColumnType::SmallUnsigned => self
+                .try_get::<Option<u16>>(pre, col)?
+                .map(Value::SmallUnsigned),
+            ColumnType::Unsigned => self
+                .try_get::<Option<u32>>(pre, col)?
+                .map(Value::Unsigned),
+            ColumnType::BigUnsigned => self
+                .try_get::<Option<u64>>(pre, col)?
+                .map(Value::BigUnsigned),
+            ColumnType::Float => self.try_get::<Option<f32>>(pre, col)?.map(Value::Float),
+            ColumnType::Double => self.try_get::<Option<f64>>(pre, col)?.map(Value::Double),
+            ColumnType::String => self
+                .try_get::<Option<String>>(pre, col)?
+                .map(Value::String),
+            ColumnType::Bytes => self.try_get_bytes(pre, col)?.map(Value::Bytes),
+            #[cfg(feature = "with-json")]
+            ColumnType::Json => self
+                .try_get::<Option<serde_json::Value>>(pre, col)?
+                .map(Value::Json),
+            #[cfg(feature = "with-chrono")]
+            ColumnType::ChronoDate => self
+                .try_get::<Option<chrono::NaiveDate>>(pre, col)?
+                .map(Value::ChronoDate),
+            #[cfg(feature = "with-chrono")]
+            ColumnType::ChronoTime => self
+                .try_get::<Option<chrono::NaiveTime>>(pre, col)?
+                .map(Value::ChronoTime),
+            #[cfg(feature = "with-chrono")]
+            ColumnType::ChronoDateTime => self
+                .try_get::<Option<chrono::NaiveDateTime>>(pre, col)?
+                .map(Value::ChronoDateTime),
+            #[cfg(feature = "with-chrono")]
+            ColumnType::ChronoDateTimeUtc => self
+                .try_get::<Option<chrono::DateTime<chrono::Utc>>>(pre, col)?
+                .map(Value::ChronoDateTimeUtc),
+            #[cfg(feature = "with-chrono")]
+            ColumnType::ChronoDateTimeLocal => self
+                .try_get::<Option<chrono::DateTime<chrono::Local>>>(pre, col)?
+                .map(Value::ChronoDateTimeLocal),
+            #[cfg(feature = "with-time")]
+            ColumnType::TimeDate => self
+                .try_get::<Option<time::Date>>(pre, col)?
+                .map(Value::TimeDate),
+            #[cfg(feature = "with-time")]
+            ColumnType::TimeTime => self
+                .try_get::<Option<time::Time>>(pre, col)?
+                .map(Value::TimeTime),
+            #[cfg(feature = "with-time")]
+            ColumnType::TimeDateTime => self
+                .try_get::<Option<time::PrimitiveDateTime>>(pre, col)?
+                .map(Value::TimeDateTime),
+            #[cfg(feature = "with-time")]
+            ColumnType::TimeDateTimeWithTimeZone => self
+                .try_get::<Option<time::OffsetDateTime>>(pre, col)?
+                .map(Value::TimeDateTimeWithTimeZone),
+            #[cfg(feature = "with-rust_decimal")]
+            ColumnType::Decimal => self
+                .try_get::<Option<rust_decimal::Decimal>>(pre, col)?
+                .map(Value::Decimal),
+            #[cfg(feature = "with-bigdecimal")]
+            ColumnType::BigDecimal => self
+                .try_get::<Option<bigdecimal::BigDecimal>>(pre, col)?
+                .map(Value::BigDecimal),
+            #[cfg(feature = "with-uuid")]
+            ColumnType::Uuid => self.try_get::<Option<uuid::Uuid>>(pre, col)?.map(Value::Uuid),
+        };
+        Ok(value.unwrap_or(Value::Null))
+    }
+}
+
+/// The SQL type of a column, as reported by the database driver at runtime.
+///
+/// Unlike the schema-level `ColumnType` used by the entity model, this is built
+/// from whatever type name the driver returns for an ad-hoc query, so it only
+/// describes the shapes [`Value`] can hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    Bool,
+    TinyInt,
+    SmallInt,
+    Int,
+    BigInt,
+    TinyUnsigned,
+    SmallUnsigned,
+    Unsigned,
+    BigUnsigned,
+    Float,
+    Double,
+    String,
+    Bytes,
+    #[cfg(feature = "with-json")]
+    Json,
+    #[cfg(feature = "with-chrono")]
+    ChronoDate,
+    #[cfg(feature = "with-chrono")]
+    ChronoTime,
+    #[cfg(feature = "with-chrono")]
+    ChronoDateTime,
+    /// A timezone-aware timestamp (Postgres `TIMESTAMPTZ`). Decoded via
+    /// `DateTime<Utc>`, never `NaiveDateTime` — sqlx's Postgres driver rejects a
+    /// naive decode of a `TIMESTAMPTZ` column's type OID.
+    #[cfg(feature = "with-chrono")]
+    ChronoDateTimeUtc,
+    /// Not reachable from [`Self::from_type_name`] — no driver reports a
+    /// fixed-to-local-offset timestamp type — but still needed so the mock
+    /// backend can round-trip a `Value::ChronoDateTimeLocal`.
+    #[cfg(feature = "with-chrono")]
+    ChronoDateTimeLocal,
+    /// `DATE`, decoded via the `time` crate. Only reachable when `with-time` is
+    /// enabled without `with-chrono`, which takes priority when both are on.
+    #[cfg(feature = "with-time")]
+    TimeDate,
+    #[cfg(feature = "with-time")]
+    TimeTime,
+    #[cfg(feature = "with-time")]
+    TimeDateTime,
+    #[cfg(feature = "with-time")]
+    TimeDateTimeWithTimeZone,
+    #[cfg(feature = "with-rust_decimal")]
+    Decimal,
+    /// Not reachable from [`Self::from_type_name`] — `"DECIMAL"`/`"NUMERIC"` map
+    /// to `Self::Decimal` when `with-rust_decimal` is on and to [`Self::String`]
+    /// otherwise — but still needed so the mock backend can round-trip a
+    /// `Value::BigDecimal`.
+    #[cfg(feature = "with-bigdecimal")]
+    BigDecimal,
+    #[cfg(feature = "with-uuid")]
+    Uuid,
 }
 
+impl ColumnType {
+    /// Map a driver-reported type name (e.g. `"INT4"`, `"VARCHAR"`, `"BOOL"`) onto
+    /// the [`ColumnType`] used to decode it. Unrecognised names fall back to
+    /// `String`, since every backend can read an unknown column as text.
+    fn from_type_name(name: &str) -> Self {
+        match name.to_ascii_uppercase().as_str() {
+            "BOOL" | "BOOLEAN" => Self::Bool,
+            "TINYINT" | "INT1" => Self::TinyInt,
+            "SMALLINT" | "INT2" => Self::SmallInt,
+            "INT" | "INTEGER" | "INT4" | "MEDIUMINT" => Self::Int,
+            "BIGINT" | "INT8" => Self::BigInt,
+            "TINYINT UNSIGNED" => Self::TinyUnsigned,
+            "SMALLINT UNSIGNED" => Self::SmallUnsigned,
+            "INT UNSIGNED" | "MEDIUMINT UNSIGNED" => Self::Unsigned,
+            "BIGINT UNSIGNED" => Self::BigUnsigned,
+            "FLOAT" | "FLOAT4" | "REAL" => Self::Float,
+            "DOUBLE" | "FLOAT8" | "DOUBLE PRECISION" => Self::Double,
+            "BLOB" | "BYTEA" | "BINARY" | "VARBINARY" => Self::Bytes,
+            #[cfg(feature = "with-json")]
+            "JSON" | "JSONB" => Self::Json,
+            #[cfg(feature = "with-chrono")]
+            "DATE" => Self::ChronoDate,
+            #[cfg(all(feature = "with-time", not(feature = "with-chrono")))]
+            "DATE" => Self::TimeDate,
+            #[cfg(feature = "with-chrono")]
+            "TIME" => Self::ChronoTime,
+            #[cfg(all(feature = "with-time", not(feature = "with-chrono")))]
+            "TIME" => Self::TimeTime,
+            #[cfg(feature = "with-chrono")]
+            "DATETIME" | "TIMESTAMP" => Self::ChronoDateTime,
+            #[cfg(all(feature = "with-time", not(feature = "with-chrono")))]
+            "DATETIME" | "TIMESTAMP" => Self::TimeDateTime,
+            #[cfg(feature = "with-chrono")]
+            "TIMESTAMPTZ" => Self::ChronoDateTimeUtc,
+            #[cfg(all(feature = "with-time", not(feature = "with-chrono")))]
+            "TIMESTAMPTZ" => Self::TimeDateTimeWithTimeZone,
+            #[cfg(feature = "with-rust_decimal")]
+            "DECIMAL" | "NUMERIC" => Self::Decimal,
+            #[cfg(feature = "with-uuid")]
+            "UUID" => Self::Uuid,
+            _ => Self::String,
+        }
+    }
+
+    /// Inverse of [`Self::from_type_name`] for the mock backend, which already
+    /// stores columns as a typed [`Value`] rather than a type-name string.
+    pub(crate) fn from_value(value: &Value) -> Self {
+        match value {
+            Value::Bool(_) => Self::Bool,
+            Value::TinyInt(_) => Self::TinyInt,
+            Value::SmallInt(_) => Self::SmallInt,
+            Value::Int(_) => Self::Int,
+            Value::BigInt(_) => Self::BigInt,
+            Value::TinyUnsigned(_) => Self::TinyUnsigned,
+            Value::SmallUnsigned(_) => Self::SmallUnsigned,
+            Value::Unsigned(_) => Self::Unsigned,
+            Value::BigUnsigned(_) => Self::BigUnsigned,
+            Value::Float(_) => Self::Float,
+            Value::Double(_) => Self::Double,
+            Value::String(_) => Self::String,
+            Value::Bytes(_) => Self::Bytes,
+            #[cfg(feature = "with-json")]
+            Value::Json(_) => Self::Json,
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDate(_) => Self::ChronoDate,
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoTime(_) => Self::ChronoTime,
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDateTime(_) => Self::ChronoDateTime,
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDateTimeUtc(_) => Self::ChronoDateTimeUtc,
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDateTimeLocal(_) => Self::ChronoDateTimeLocal,
+            #[cfg(feature = "with-time")]
+            Value::TimeDate(_) => Self::TimeDate,
+            #[cfg(feature = "with-time")]
+            Value::TimeTime(_) => Self::TimeTime,
+            #[cfg(feature = "with-time")]
+            Value::TimeDateTime(_) => Self::TimeDateTime,
+            #[cfg(feature = "with-time")]
+            Value::TimeDateTimeWithTimeZone(_) => Self::TimeDateTimeWithTimeZone,
+            #[cfg(feature = "with-rust_decimal")]
+            Value::Decimal(_) => Self::Decimal,
+            #[cfg(feature = "with-bigdecimal")]
+            Value::BigDecimal(_) => Self::BigDecimal,
+            #[cfg(feature = "with-uuid")]
+            Value::Uuid(_) => Self::Uuid,
+            Value::Null => Self::String,
+        }
+    }
+}
+
+/// An owned, dynamically-typed column value, as produced by
+/// [`QueryResult::try_get_value`].
+#[derive(Debug, Clone)]
+pub enum Value {
+    Bool(bool),
+    TinyInt(i8),
+    SmallInt(i16),
+    Int(i32),
+    BigInt(i64),
+    TinyUnsigned(u8),
+    SmallUnsigned(u16),
+    Unsigned(u32),
+    BigUnsigned(u64),
+    Float(f32),
+    Double(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    #[cfg(feature = "with-json")]
+    Json(serde_json::Value),
+    #[cfg(feature = "with-chrono")]
+    ChronoDate(chrono::NaiveDate),
+    #[cfg(feature = "with-chrono")]
+    ChronoTime(chrono::NaiveTime),
+    #[cfg(feature = "with-chrono")]
+    ChronoDateTime(chrono::NaiveDateTime),
+    #[cfg(feature = "with-chrono")]
+    ChronoDateTimeUtc(chrono::DateTime<chrono::Utc>),
+    #[cfg(feature = "with-chrono")]
+    ChronoDateTimeLocal(chrono::DateTime<chrono::Local>),
+    #[cfg(feature = "with-time")]
+    TimeDate(time::Date),
+    #[cfg(feature = "with-time")]
+    TimeTime(time::Time),
+    #[cfg(feature = "with-time")]
+    TimeDateTime(time::PrimitiveDateTime),
+    #[cfg(feature = "with-time")]
+    TimeDateTimeWithTimeZone(time::OffsetDateTime),
+    #[cfg(feature = "with-rust_decimal")]
+    Decimal(rust_decimal::Decimal),
+    #[cfg(feature = "with-bigdecimal")]
+    BigDecimal(bigdecimal::BigDecimal),
+    #[cfg(feature = "with-uuid")]
+    Uuid(uuid::Uuid),
+    Null,
+}
+
+// `MockRow` stores each column as a `Value` and decodes it generically via
+// `TryFrom`, mirroring how the sqlx-backed rows decode via `TryGetable`.
+macro_rules! impl_try_from_value {
+    ( $variant: ident, $type: ty ) => {
+        impl std::convert::TryFrom<Value> for $type {
+            type Error = ();
+
+            fn try_from(v: Value) -> Result<Self, Self::Error> {
+                match v {
+                    Value::$variant(inner) => Ok(inner),
+                    _ => Err(()),
+                }
+            }
+        }
+    };
+}
+
+impl_try_from_value!(Bool, bool);
+impl_try_from_value!(TinyInt, i8);
+impl_try_from_value!(SmallInt, i16);
+impl_try_from_value!(Int, i32);
+impl_try_from_value!(BigInt, i64);
+impl_try_from_value!(TinyUnsigned, u8);
+impl_try_from_value!(SmallUnsigned, u16);
+impl_try_from_value!(Unsigned, u32);
+impl_try_from_value!(BigUnsigned, u64);
+impl_try_from_value!(Float, f32);
+impl_try_from_value!(Double, f64);
+impl_try_from_value!(String, String);
+impl_try_from_value!(Bytes, Vec<u8>);
+
+#[cfg(feature = "with-json")]
+impl_try_from_value!(Json, serde_json::Value);
+
+#[cfg(feature = "with-chrono")]
+impl_try_from_value!(ChronoDate, chrono::NaiveDate);
+
+#[cfg(feature = "with-chrono")]
+impl_try_from_value!(ChronoTime, chrono::NaiveTime);
+
+#[cfg(feature = "with-chrono")]
+impl_try_from_value!(ChronoDateTime, chrono::NaiveDateTime);
+
+#[cfg(feature = "with-chrono")]
+impl_try_from_value!(ChronoDateTimeUtc, chrono::DateTime<chrono::Utc>);
+
+#[cfg(feature = "with-chrono")]
+impl_try_from_value!(ChronoDateTimeLocal, chrono::DateTime<chrono::Local>);
+
+#[cfg(feature = "with-time")]
+impl_try_from_value!(TimeDate, time::Date);
+
+#[cfg(feature = "with-time")]
+impl_try_from_value!(TimeTime, time::Time);
+
+#[cfg(feature = "with-time")]
+impl_try_from_value!(TimeDateTime, time::PrimitiveDateTime);
+
+#[cfg(feature = "with-time")]
+impl_try_from_value!(TimeDateTimeWithTimeZone, time::OffsetDateTime);
+
+#[cfg(feature = "with-rust_decimal")]
+impl_try_from_value!(Decimal, rust_decimal::Decimal);
+
+#[cfg(feature = "with-bigdecimal")]
+impl_try_from_value!(BigDecimal, bigdecimal::BigDecimal);
+
+#[cfg(feature = "with-uuid")]
+impl_try_from_value!(Uuid, uuid::Uuid);
+
 impl fmt::Debug for QueryResultRow {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -75,6 +541,16 @@ impl<T: TryGetable> TryGetable for Option<T> {
     }
 }
 
+impl<T: TryGetableByIndex> TryGetableByIndex for Option<T> {
+    fn try_get_by_index(res: &QueryResult, index: usize) -> Result<Self, TryGetError> {
+        match T::try_get_by_index(res, index) {
+            Ok(v) => Ok(Some(v)),
+            Err(TryGetError::Null) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
 macro_rules! try_getable_all {
     ( $type: ty ) => {
         impl TryGetable for $type {
@@ -202,7 +678,78 @@ macro_rules! try_getable_postgres {
                         panic!("{} unsupported by sqlx-sqlite", stringify!($type))
                     }
                     #[cfg(feature = "mock")]
-                    QueryResultRow::Mock(row) => row.try_get(column.as_str()).map_err(|e| {
+                    QueryResultRow::Mock(_) => Err(TryGetError::DbErr(DbErr::Query(format!(
+                        "{} unsupported by the mock backend",
+                        stringify!($type)
+                    )))),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! try_getable_by_index_all {
+    ( $type: ty ) => {
+        impl TryGetableByIndex for $type {
+            fn try_get_by_index(res: &QueryResult, index: usize) -> Result<Self, TryGetError> {
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(index)
+                            .map_err(crate::sqlx_error_to_query_err)
+                            .and_then(|opt| opt.ok_or_else(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(index)
+                            .map_err(crate::sqlx_error_to_query_err)
+                            .and_then(|opt| opt.ok_or_else(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(index)
+                            .map_err(crate::sqlx_error_to_query_err)
+                            .and_then(|opt| opt.ok_or_else(TryGetError::Null))
+                    }
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(row) => row.try_get_by_index(index).map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        TryGetError::Null
+                    }),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! try_getable_by_index_unsigned {
+    ( $type: ty ) => {
+        impl TryGetableByIndex for $type {
+            fn try_get_by_index(res: &QueryResult, index: usize) -> Result<Self, TryGetError> {
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(index)
+                            .map_err(crate::sqlx_error_to_query_err)
+                            .and_then(|opt| opt.ok_or_else(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(_) => {
+                        panic!("{} unsupported by sqlx-postgres", stringify!($type))
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(index)
+                            .map_err(crate::sqlx_error_to_query_err)
+                            .and_then(|opt| opt.ok_or_else(TryGetError::Null))
+                    }
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(row) => row.try_get_by_index(index).map_err(|e| {
                         debug_print!("{:#?}", e.to_string());
                         TryGetError::Null
                     }),
@@ -212,6 +759,68 @@ macro_rules! try_getable_postgres {
     };
 }
 
+macro_rules! try_getable_by_index_mysql {
+    ( $type: ty ) => {
+        impl TryGetableByIndex for $type {
+            fn try_get_by_index(res: &QueryResult, index: usize) -> Result<Self, TryGetError> {
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(index)
+                            .map_err(crate::sqlx_error_to_query_err)
+                            .and_then(|opt| opt.ok_or_else(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(_) => {
+                        panic!("{} unsupported by sqlx-postgres", stringify!($type))
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(_) => {
+                        panic!("{} unsupported by sqlx-sqlite", stringify!($type))
+                    }
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(row) => row.try_get_by_index(index).map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        TryGetError::Null
+                    }),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! try_getable_by_index_postgres {
+    ( $type: ty ) => {
+        impl TryGetableByIndex for $type {
+            fn try_get_by_index(res: &QueryResult, index: usize) -> Result<Self, TryGetError> {
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(_) => {
+                        panic!("{} unsupported by sqlx-mysql", stringify!($type))
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(index)
+                            .map_err(crate::sqlx_error_to_query_err)
+                            .and_then(|opt| opt.ok_or_else(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(_) => {
+                        panic!("{} unsupported by sqlx-sqlite", stringify!($type))
+                    }
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(_) => Err(TryGetError::DbErr(DbErr::Query(format!(
+                        "{} unsupported by the mock backend",
+                        stringify!($type)
+                    )))),
+                }
+            }
+        }
+    };
+}
+
 try_getable_all!(bool);
 try_getable_all!(i8);
 try_getable_all!(i16);
@@ -225,56 +834,412 @@ try_getable_all!(f32);
 try_getable_all!(f64);
 try_getable_all!(String);
 
+try_getable_by_index_all!(bool);
+try_getable_by_index_all!(i8);
+try_getable_by_index_all!(i16);
+try_getable_by_index_all!(i32);
+try_getable_by_index_all!(i64);
+try_getable_by_index_unsigned!(u8);
+try_getable_by_index_unsigned!(u16);
+try_getable_by_index_all!(u32);
+try_getable_by_index_mysql!(u64);
+try_getable_by_index_all!(f32);
+try_getable_by_index_all!(f64);
+try_getable_by_index_all!(String);
+
 #[cfg(feature = "with-json")]
 try_getable_all!(serde_json::Value);
 
+#[cfg(feature = "with-json")]
+try_getable_by_index_all!(serde_json::Value);
+
 #[cfg(feature = "with-chrono")]
 try_getable_all!(chrono::NaiveDateTime);
 
+#[cfg(feature = "with-chrono")]
+try_getable_by_index_all!(chrono::NaiveDateTime);
+
+#[cfg(feature = "with-chrono")]
+try_getable_all!(chrono::NaiveDate);
+
+#[cfg(feature = "with-chrono")]
+try_getable_by_index_all!(chrono::NaiveDate);
+
+#[cfg(feature = "with-chrono")]
+try_getable_all!(chrono::NaiveTime);
+
+#[cfg(feature = "with-chrono")]
+try_getable_by_index_all!(chrono::NaiveTime);
+
 #[cfg(feature = "with-chrono")]
 try_getable_postgres!(chrono::DateTime<chrono::FixedOffset>);
 
+#[cfg(feature = "with-chrono")]
+try_getable_by_index_postgres!(chrono::DateTime<chrono::FixedOffset>);
+
+// Only Postgres's `TIMESTAMPTZ` carries a timezone; MySQL/Sqlite `DATETIME` columns
+// are naive, so reading them as `DateTime<Utc>`/`DateTime<Local>` would silently
+// assume an offset instead of reading one. Error out on those backends rather than
+// decode garbage.
+macro_rules! try_getable_postgres_err {
+    ( $type: ty ) => {
+        impl TryGetable for $type {
+            fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError> {
+                let column = format!("{}{}", pre, col);
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(_) => Err(TryGetError::DbErr(DbErr::Query(
+                        format!("{} unsupported by sqlx-mysql", stringify!($type)),
+                    ))),
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(column.as_str())
+                            .map_err(crate::sqlx_error_to_query_err)
+                            .and_then(|opt| opt.ok_or_else(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(_) => Err(TryGetError::DbErr(DbErr::Query(
+                        format!("{} unsupported by sqlx-sqlite", stringify!($type)),
+                    ))),
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(row) => row.try_get(column.as_str()).map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        TryGetError::Null
+                    }),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! try_getable_by_index_postgres_err {
+    ( $type: ty ) => {
+        impl TryGetableByIndex for $type {
+            fn try_get_by_index(res: &QueryResult, index: usize) -> Result<Self, TryGetError> {
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(_) => Err(TryGetError::DbErr(DbErr::Query(
+                        format!("{} unsupported by sqlx-mysql", stringify!($type)),
+                    ))),
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(index)
+                            .map_err(crate::sqlx_error_to_query_err)
+                            .and_then(|opt| opt.ok_or_else(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(_) => Err(TryGetError::DbErr(DbErr::Query(
+                        format!("{} unsupported by sqlx-sqlite", stringify!($type)),
+                    ))),
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(row) => row.try_get_by_index(index).map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        TryGetError::Null
+                    }),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "with-chrono")]
+try_getable_postgres_err!(chrono::DateTime<chrono::Utc>);
+
+#[cfg(feature = "with-chrono")]
+try_getable_by_index_postgres_err!(chrono::DateTime<chrono::Utc>);
+
+#[cfg(feature = "with-chrono")]
+try_getable_postgres_err!(chrono::DateTime<chrono::Local>);
+
+#[cfg(feature = "with-chrono")]
+try_getable_by_index_postgres_err!(chrono::DateTime<chrono::Local>);
+
+#[cfg(feature = "with-time")]
+try_getable_all!(time::Date);
+
+#[cfg(feature = "with-time")]
+try_getable_by_index_all!(time::Date);
+
+#[cfg(feature = "with-time")]
+try_getable_all!(time::Time);
+
+#[cfg(feature = "with-time")]
+try_getable_by_index_all!(time::Time);
+
+#[cfg(feature = "with-time")]
+try_getable_all!(time::PrimitiveDateTime);
+
+#[cfg(feature = "with-time")]
+try_getable_by_index_all!(time::PrimitiveDateTime);
+
+#[cfg(feature = "with-time")]
+try_getable_postgres_err!(time::OffsetDateTime);
+
+#[cfg(feature = "with-time")]
+try_getable_by_index_postgres_err!(time::OffsetDateTime);
+
+// Shared by every arbitrary-precision decimal type (`rust_decimal`, `bigdecimal`):
+// every backend decodes the same way except sqlite, which has no native decimal
+// type and is read back through `f64` via $from_f64.
+macro_rules! try_getable_decimal {
+    ( $type: ty, $from_f64: expr ) => {
+        impl TryGetable for $type {
+            fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError> {
+                let column = format!("{}{}", pre, col);
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(column.as_str())
+                            .map_err(crate::sqlx_error_to_query_err)
+                            .and_then(|opt| opt.ok_or_else(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(column.as_str())
+                            .map_err(crate::sqlx_error_to_query_err)
+                            .and_then(|opt| opt.ok_or_else(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(row) => {
+                        use sqlx::Row;
+                        let val: Option<f64> = row
+                            .try_get(column.as_str())
+                            .map_err(crate::sqlx_error_to_query_err)?;
+                        match val {
+                            Some(v) => ($from_f64)(v).ok_or_else(|| {
+                                TryGetError::DbErr(DbErr::Query(format!(
+                                    "Failed to convert f64 into {}",
+                                    stringify!($type)
+                                )))
+                            }),
+                            None => Err(TryGetError::Null),
+                        }
+                    }
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(row) => row.try_get(column.as_str()).map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        TryGetError::Null
+                    }),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! try_getable_by_index_decimal {
+    ( $type: ty, $from_f64: expr ) => {
+        impl TryGetableByIndex for $type {
+            fn try_get_by_index(res: &QueryResult, index: usize) -> Result<Self, TryGetError> {
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(index)
+                            .map_err(crate::sqlx_error_to_query_err)
+                            .and_then(|opt| opt.ok_or_else(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(index)
+                            .map_err(crate::sqlx_error_to_query_err)
+                            .and_then(|opt| opt.ok_or_else(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(row) => {
+                        use sqlx::Row;
+                        let val: Option<f64> = row
+                            .try_get(index)
+                            .map_err(crate::sqlx_error_to_query_err)?;
+                        match val {
+                            Some(v) => ($from_f64)(v).ok_or_else(|| {
+                                TryGetError::DbErr(DbErr::Query(format!(
+                                    "Failed to convert f64 into {}",
+                                    stringify!($type)
+                                )))
+                            }),
+                            None => Err(TryGetError::Null),
+                        }
+                    }
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(row) => row.try_get_by_index(index).map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        TryGetError::Null
+                    }),
+                }
+            }
+        }
+    };
+}
+
 #[cfg(feature = "with-rust_decimal")]
-use rust_decimal::Decimal;
+try_getable_decimal!(rust_decimal::Decimal, |v: f64| {
+    use rust_decimal::prelude::FromPrimitive;
+    rust_decimal::Decimal::from_f64(v)
+});
 
 #[cfg(feature = "with-rust_decimal")]
-impl TryGetable for Decimal {
+try_getable_by_index_decimal!(rust_decimal::Decimal, |v: f64| {
+    use rust_decimal::prelude::FromPrimitive;
+    rust_decimal::Decimal::from_f64(v)
+});
+
+#[cfg(feature = "with-uuid")]
+try_getable_all!(uuid::Uuid);
+
+#[cfg(feature = "with-uuid")]
+try_getable_by_index_all!(uuid::Uuid);
+
+#[cfg(feature = "with-ipnetwork")]
+try_getable_postgres!(ipnetwork::IpNetwork);
+
+#[cfg(feature = "with-ipnetwork")]
+try_getable_by_index_postgres!(ipnetwork::IpNetwork);
+
+#[cfg(feature = "with-ipnetwork")]
+try_getable_postgres!(std::net::IpAddr);
+
+#[cfg(feature = "with-ipnetwork")]
+try_getable_by_index_postgres!(std::net::IpAddr);
+
+#[cfg(feature = "with-mac_address")]
+try_getable_postgres!(mac_address::MacAddress);
+
+#[cfg(feature = "with-mac_address")]
+try_getable_by_index_postgres!(mac_address::MacAddress);
+
+#[cfg(feature = "with-bigdecimal")]
+try_getable_decimal!(bigdecimal::BigDecimal, |v: f64| bigdecimal::BigDecimal::try_from(v).ok());
+
+#[cfg(feature = "with-bigdecimal")]
+try_getable_by_index_decimal!(bigdecimal::BigDecimal, |v: f64| bigdecimal::BigDecimal::try_from(v)
+    .ok());
+
+/// An adapter that stores `T` in a plain `TEXT` column by round-tripping it through
+/// its `Display`/`FromStr` implementation.
+///
+/// This is handy for types that have no native column type (enums, URLs, semver
+/// versions, ...) but do implement `FromStr`, following sqlx's `Text` wrapper.
+///
+/// ```ignore
+/// let version: Text<semver::Version> = query_result.try_get(pre, "version")?;
+/// let version: semver::Version = version.0;
+/// ```
+pub struct Text<T>(pub T);
+
+impl<T> TryGetable for Text<T>
+where
+    T: std::str::FromStr,
+{
+    fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError> {
+        let string = String::try_get(res, pre, col)?;
+        T::from_str(&string).map(Text).map_err(|_| {
+            TryGetError::DbErr(DbErr::Query(format!(
+                "Failed to parse \"{}\" as the target type",
+                string
+            )))
+        })
+    }
+}
+
+impl<T> fmt::Display for Text<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// The write side of the adapter: round-trips `T` back through `Display` so a
+/// `Text<T>` can be bound as an ordinary `TEXT` value in an INSERT/UPDATE.
+impl<T> From<Text<T>> for Value
+where
+    T: fmt::Display,
+{
+    fn from(text: Text<T>) -> Self {
+        Value::String(text.0.to_string())
+    }
+}
+
+impl<T> TryGetableByIndex for Text<T>
+where
+    T: std::str::FromStr,
+{
+    fn try_get_by_index(res: &QueryResult, index: usize) -> Result<Self, TryGetError> {
+        let string = String::try_get_by_index(res, index)?;
+        T::from_str(&string).map(Text).map_err(|_| {
+            TryGetError::DbErr(DbErr::Query(format!(
+                "Failed to parse \"{}\" as the target type",
+                string
+            )))
+        })
+    }
+}
+
+// Only Postgres has a native array type; sqlx already knows how to decode it for
+// every scalar type covered by `try_getable_all!` above, so we just delegate to it.
+#[cfg(feature = "sqlx-postgres")]
+impl<T> TryGetable for Vec<T>
+where
+    T: sqlx::postgres::PgHasArrayType,
+    T: for<'r> sqlx::Decode<'r, sqlx::Postgres> + sqlx::Type<sqlx::Postgres>,
+{
     fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError> {
         let column = format!("{}{}", pre, col);
         match &res.row {
             #[cfg(feature = "sqlx-mysql")]
-            QueryResultRow::SqlxMySql(row) => {
+            QueryResultRow::SqlxMySql(_) => Err(TryGetError::DbErr(DbErr::Query(
+                "Array types are unsupported by sqlx-mysql".to_owned(),
+            ))),
+            QueryResultRow::SqlxPostgres(row) => {
                 use sqlx::Row;
-                row.try_get::<Option<Decimal>, _>(column.as_str())
+                row.try_get::<Option<Vec<T>>, _>(column.as_str())
                     .map_err(crate::sqlx_error_to_query_err)
+                    .and_then(|opt| opt.ok_or_else(TryGetError::Null))
             }
-            #[cfg(feature = "sqlx-postgres")]
+            #[cfg(feature = "sqlx-sqlite")]
+            QueryResultRow::SqlxSqlite(_) => Err(TryGetError::DbErr(DbErr::Query(
+                "Array types are unsupported by sqlx-sqlite".to_owned(),
+            ))),
+            #[cfg(feature = "mock")]
+            QueryResultRow::Mock(_) => Err(TryGetError::DbErr(DbErr::Query(
+                "Array types are unsupported by the mock backend".to_owned(),
+            ))),
+        }
+    }
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl<T> TryGetableByIndex for Vec<T>
+where
+    T: sqlx::postgres::PgHasArrayType,
+    T: for<'r> sqlx::Decode<'r, sqlx::Postgres> + sqlx::Type<sqlx::Postgres>,
+{
+    fn try_get_by_index(res: &QueryResult, index: usize) -> Result<Self, TryGetError> {
+        match &res.row {
+            #[cfg(feature = "sqlx-mysql")]
+            QueryResultRow::SqlxMySql(_) => Err(TryGetError::DbErr(DbErr::Query(
+                "Array types are unsupported by sqlx-mysql".to_owned(),
+            ))),
             QueryResultRow::SqlxPostgres(row) => {
                 use sqlx::Row;
-                row.try_get::<Option<Decimal>, _>(column.as_str())
+                row.try_get::<Option<Vec<T>>, _>(index)
                     .map_err(crate::sqlx_error_to_query_err)
+                    .and_then(|opt| opt.ok_or_else(TryGetError::Null))
             }
             #[cfg(feature = "sqlx-sqlite")]
-            QueryResultRow::SqlxSqlite(row) => {
-                use sqlx::Row;
-                let val: Option<f64> = row
-                    .try_get(column.as_str())
-                    .map_err(crate::sqlx_error_to_query_err)?;
-                use rust_decimal::prelude::FromPrimitive;
-                match val {
-                    Some(v) => Decimal::from_f64(v)
-                        .ok_or_else(|| DbErr::Query("Failed to convert f64 into Decimal".to_owned())),
-                    None => Err(TryGetError::Null)
-                }
-            }
+            QueryResultRow::SqlxSqlite(_) => Err(TryGetError::DbErr(DbErr::Query(
+                "Array types are unsupported by sqlx-sqlite".to_owned(),
+            ))),
             #[cfg(feature = "mock")]
-            QueryResultRow::Mock(row) => row.try_get(column.as_str()).map_err(|e| {
-                debug_print!("{:#?}", e.to_string());
-                TryGetError::Null
-            }),
+            QueryResultRow::Mock(_) => Err(TryGetError::DbErr(DbErr::Query(
+                "Array types are unsupported by the mock backend".to_owned(),
+            ))),
         }
     }
 }
-
-#[cfg(feature = "with-uuid")]
-try_getable_all!(uuid::Uuid);